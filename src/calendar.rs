@@ -0,0 +1,108 @@
+//! Maps NTU "Teaching Wk" numbers onto concrete calendar dates.
+//!
+//! `Class.weeks` and `Course.exam` only carry abstract teaching-week
+//! integers; an [`AcademicCalendar`] anchors those integers to a real
+//! semester so they can be placed on a timeline.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Describes how a semester's teaching weeks map onto calendar weeks.
+///
+/// `week1_start` is the Monday of teaching week 1. `inserted_weeks` lists
+/// non-teaching weeks (recess, reading/exam weeks) that NTU inserts into the
+/// calendar without being counted in "Teaching Wk" numbering, shifting every
+/// later teaching week forward by one calendar week.
+#[derive(Debug, Clone)]
+pub struct AcademicCalendar {
+    pub week1_start: NaiveDate,
+    pub inserted_weeks: Vec<u32>,
+}
+
+impl AcademicCalendar {
+    pub fn new(week1_start: NaiveDate) -> Self {
+        Self {
+            week1_start,
+            inserted_weeks: Vec::new(),
+        }
+    }
+}
+
+/// Resolves `week` (a "Teaching Wk" number) and `weekday` to a concrete date.
+///
+/// `week` is 1-based, matching the "Teaching Wk" numbers NTU publishes;
+/// passing `0` panics rather than underflowing.
+pub fn week_to_date(config: &AcademicCalendar, week: u32, weekday: Weekday) -> NaiveDate {
+    assert!(week >= 1, "teaching week numbers are 1-based, got {week}");
+    let inserted_before = config
+        .inserted_weeks
+        .iter()
+        .filter(|&&inserted| inserted <= week)
+        .count() as u32;
+    let calendar_week = week - 1 + inserted_before;
+    let week_start = config.week1_start + Duration::weeks(calendar_week as i64);
+    week_start + Duration::days(weekday.num_days_from_monday() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_calendar() -> AcademicCalendar {
+        AcademicCalendar::new(NaiveDate::from_ymd_opt(2024, 1, 8).unwrap())
+    }
+
+    #[test]
+    fn week_one_monday_is_the_anchor() {
+        let config = sample_calendar();
+        assert_eq!(
+            week_to_date(&config, 1, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2024, 1, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn week_one_friday_is_four_days_after_the_anchor() {
+        let config = sample_calendar();
+        assert_eq!(
+            week_to_date(&config, 1, Weekday::Fri),
+            NaiveDate::from_ymd_opt(2024, 1, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn weeks_without_inserted_weeks_advance_by_seven_days() {
+        let config = sample_calendar();
+        assert_eq!(
+            week_to_date(&config, 3, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2024, 1, 22).unwrap()
+        );
+    }
+
+    #[test]
+    fn week_immediately_before_an_inserted_week_is_unaffected() {
+        let mut config = sample_calendar();
+        config.inserted_weeks.push(7);
+        assert_eq!(
+            week_to_date(&config, 6, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2024, 2, 12).unwrap()
+        );
+    }
+
+    #[test]
+    fn week_after_an_inserted_week_is_shifted_forward_by_one_calendar_week() {
+        let mut config = sample_calendar();
+        config.inserted_weeks.push(7);
+        // Without the recess shift this would land on 2024-02-19.
+        assert_eq!(
+            week_to_date(&config, 7, Weekday::Mon),
+            NaiveDate::from_ymd_opt(2024, 2, 26).unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "1-based")]
+    fn week_zero_panics_instead_of_underflowing() {
+        let config = sample_calendar();
+        week_to_date(&config, 0, Weekday::Mon);
+    }
+}
@@ -33,19 +33,19 @@ pub struct Exam {
     pub day: u32,
     pub month: chrono::Month,
     pub year: u32,
-    pub peroid: Period,
+    pub period: Period,
 }
 
 #[derive(Debug)]
 pub struct Period {
-    start: Time,
-    end: Time,
+    pub(crate) start: Time,
+    pub(crate) end: Time,
 }
 
 #[derive(Debug)]
 pub struct Time {
-    hour: u32,
-    minute: u32,
+    pub(crate) hour: u32,
+    pub(crate) minute: u32,
 }
 
 #[derive(Debug)]
@@ -145,54 +145,137 @@ impl fmt::Display for ParseExamError {
 
 impl Error for ParseExamError {}
 
-fn parse_exam(exam_raw: &str) -> Result<Exam, ParseExamError> {
-    let re = regex::Regex::new(r"/(?<day>\d{2})-(?<month>[A-Z][a-z]{2})-(?<year>[0-9]{4}) (?<start_hour>\d{2})(?<start_minute>\d{2})to(?<end_hour>\d{2})(?<end_minute>\d{2})/gm").unwrap();
-    let captures = re
-        .captures(exam_raw)
-        .ok_or(ParseExamError)
-        .into_report()
-        .attach_printable_lazy(|| "Failed to parse exam date")?;
-
-    let month = captures.name("month").unwrap().as_str();
-    let month = match month.parse() {
-        Ok(m) => m,
-        Err(_) => {
-            return Err(Report::new(ParseExamError)
-                .attach_printable(format!("Failed to parse month: {}", month)))
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind {
+    Alpha,
+    Numeric,
+    Separator,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    start: usize,
+}
+
+fn token_kind(c: char) -> TokenKind {
+    if c.is_ascii_alphabetic() {
+        TokenKind::Alpha
+    } else if c.is_ascii_digit() {
+        TokenKind::Numeric
+    } else {
+        TokenKind::Separator
+    }
+}
+
+/// Scans `input` into a stream of Alpha/Numeric/Separator tokens, tolerant of
+/// whatever separators or leading text surround the actual
+/// day-month-year/time fields. Runs of letters or digits form one token each;
+/// every separator character is a token of its own.
+fn tokenize(input: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        let kind = token_kind(c);
+        let mut end = start + c.len_utf8();
+        chars.next();
+        while let Some(&(i, next)) = chars.peek() {
+            if token_kind(next) != kind || kind == TokenKind::Separator {
+                break;
+            }
+            end = i + next.len_utf8();
+            chars.next();
         }
+        tokens.push(Token {
+            kind,
+            text: &input[start..end],
+            start,
+        });
+    }
+    tokens
+}
+
+fn exam_parse_error(raw: &str, field: &str, token: Option<&Token>) -> Report<ParseExamError> {
+    let msg = match token {
+        Some(token) => format!(
+            "Unable to resolve {} from token {:?} at byte {} in {:?}",
+            field, token.text, token.start, raw
+        ),
+        None => format!("Unable to find a token for {} in {:?}", field, raw),
     };
+    Report::new(ParseExamError).attach_printable(msg)
+}
+
+fn parse_exam(exam_raw: &str) -> Result<Exam, ParseExamError> {
+    let tokens: Vec<Token> = tokenize(exam_raw)
+        .into_iter()
+        .filter(|token| token.kind != TokenKind::Separator)
+        .collect();
+
+    let day_token = tokens
+        .iter()
+        .find(|token| token.kind == TokenKind::Numeric && token.text.len() == 2)
+        .ok_or_else(|| exam_parse_error(exam_raw, "day", None))?;
+    let month_token = tokens
+        .iter()
+        .find(|token| {
+            token.kind == TokenKind::Alpha
+                && token.text.len() == 3
+                && !token.text.eq_ignore_ascii_case("to")
+        })
+        .ok_or_else(|| exam_parse_error(exam_raw, "month", None))?;
+    let year_token = tokens
+        .iter()
+        .find(|token| {
+            token.kind == TokenKind::Numeric && token.text.len() == 4 && token.start > month_token.start
+        })
+        .ok_or_else(|| exam_parse_error(exam_raw, "year", None))?;
+    let mut time_tokens = tokens
+        .iter()
+        .filter(|token| token.kind == TokenKind::Numeric && token.text.len() == 4 && token.start > year_token.start);
+    let start_token = time_tokens
+        .next()
+        .ok_or_else(|| exam_parse_error(exam_raw, "exam start time", None))?;
+    let end_token = time_tokens
+        .next()
+        .ok_or_else(|| exam_parse_error(exam_raw, "exam end time", None))?;
+
+    let day: u32 = day_token
+        .text
+        .parse()
+        .map_err(|_| exam_parse_error(exam_raw, "day", Some(day_token)))?;
+    let month: chrono::Month = month_token
+        .text
+        .parse()
+        .map_err(|_| exam_parse_error(exam_raw, "month", Some(month_token)))?;
+    let year: u32 = year_token
+        .text
+        .parse()
+        .map_err(|_| exam_parse_error(exam_raw, "year", Some(year_token)))?;
+    if chrono::NaiveDate::from_ymd_opt(year as i32, month.number_from_month(), day).is_none() {
+        return Err(exam_parse_error(exam_raw, "day", Some(day_token)));
+    }
+    let start = split_hhmm(start_token).ok_or_else(|| exam_parse_error(exam_raw, "exam start time", Some(start_token)))?;
+    let end = split_hhmm(end_token).ok_or_else(|| exam_parse_error(exam_raw, "exam end time", Some(end_token)))?;
+
     Ok(Exam {
-        day: captures.name("day").unwrap().as_str().parse().unwrap(),
+        day,
         month,
-        year: captures.name("year").unwrap().as_str().parse().unwrap(),
-        peroid: Period {
-            start: Time {
-                hour: captures
-                    .name("start_hour")
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .unwrap(),
-                minute: captures
-                    .name("start_minute")
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .unwrap(),
-            },
-            end: Time {
-                hour: captures.name("end_hour").unwrap().as_str().parse().unwrap(),
-                minute: captures
-                    .name("end_minute")
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .unwrap(),
-            },
-        },
+        year,
+        period: Period { start, end },
     })
 }
 
+fn split_hhmm(token: &Token) -> Option<Time> {
+    let hour: u32 = token.text[0..2].parse().ok()?;
+    let minute: u32 = token.text[2..4].parse().ok()?;
+    if hour > 23 || minute > 59 {
+        return None;
+    }
+    Some(Time { hour, minute })
+}
+
 #[derive(Debug)]
 pub struct ParseCourseError;
 
@@ -301,3 +384,58 @@ fn parse_weeks(weeks_raw: &str) -> Result<Vec<u32>, ParseWeeksError> {
     }
     Ok(weeks)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_well_formed_exam() {
+        let exam = parse_exam("13-Nov-2023 0930to1130").unwrap();
+        assert_eq!(exam.day, 13);
+        assert_eq!(exam.year, 2023);
+        assert_eq!(exam.period.start.hour, 9);
+        assert_eq!(exam.period.start.minute, 30);
+        assert_eq!(exam.period.end.hour, 11);
+        assert_eq!(exam.period.end.minute, 30);
+    }
+
+    #[test]
+    fn parses_exam_with_leading_label_and_slash_delimiters() {
+        let exam = parse_exam("Exam Date/Time: /05-Jan-2024 0800to1000/").unwrap();
+        assert_eq!(exam.day, 5);
+        assert_eq!(exam.year, 2024);
+        assert_eq!(exam.period.start.hour, 8);
+        assert_eq!(exam.period.end.hour, 10);
+    }
+
+    #[test]
+    fn rejects_missing_time_range() {
+        assert!(parse_exam("13-Nov-2023").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_month() {
+        assert!(parse_exam("13-Xyz-2023 0930to1130").is_err());
+    }
+
+    #[test]
+    fn rejects_empty_string() {
+        assert!(parse_exam("").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_day() {
+        assert!(parse_exam("41-Nov-2023 0930to1130").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_hour() {
+        assert!(parse_exam("13-Nov-2023 9930to1130").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_minute() {
+        assert!(parse_exam("13-Nov-2023 0999to1130").is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Renders a standalone weekly-grid HTML page from parsed [`Course`]s, for a
+//! quick visual sanity-check of parsing before exporting to iCalendar.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+use chrono::{Datelike, Weekday};
+
+use crate::course::{Class, Course};
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+const HEADER: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Timetable</title>
+<style>
+table.timetable { border-collapse: collapse; width: 100%; }
+table.timetable th, table.timetable td { border: 1px solid #ccc; padding: 4px; vertical-align: top; }
+.block { border-radius: 4px; padding: 4px; margin-bottom: 2px; font-size: 0.85em; }
+</style>
+</head>
+<body>
+"#;
+
+/// Background colors for course blocks. Each course gets one picked by a hash
+/// of its code, so all of a course's blocks share a color; two different
+/// courses can hash to the same color.
+const COURSE_COLORS: [&str; 8] = [
+    "#e8f0fe", "#fde8e8", "#e8fde9", "#fdf6e8", "#f0e8fd", "#e8fdfb", "#fde8f6", "#f6fde8",
+];
+
+fn course_color(code: &str) -> &'static str {
+    let hash = code.bytes().fold(0u32, |acc, byte| acc.wrapping_mul(31).wrapping_add(byte as u32));
+    COURSE_COLORS[hash as usize % COURSE_COLORS.len()]
+}
+
+/// Escapes `&`, `<`, `>`, and `"` so arbitrary parsed strings can be safely
+/// interpolated into HTML output.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders `courses` as a weekly timetable grid (columns = weekday, rows =
+/// the distinct class start times). When `show_private` is `false`, each
+/// block omits `venue` and `index` so the page is safe to share publicly.
+pub fn courses_to_html(courses: &[Course], show_private: bool) -> String {
+    let mut slots: BTreeSet<(u32, u32)> = BTreeSet::new();
+    let mut by_weekday: [Vec<(&Course, &Class)>; 7] = std::array::from_fn(|_| Vec::new());
+    for course in courses {
+        for class in &course.classes {
+            slots.insert((class.period.start.hour, class.period.start.minute));
+            by_weekday[class.weekday.num_days_from_monday() as usize].push((course, class));
+        }
+    }
+    for classes in &mut by_weekday {
+        classes.sort_by_key(|(_, class)| (class.period.start.hour, class.period.start.minute));
+    }
+
+    let mut html = String::from(HEADER);
+    html.push_str("<table class=\"timetable\">\n<thead><tr><th></th>");
+    for weekday in WEEKDAYS {
+        let _ = write!(html, "<th>{}</th>", weekday);
+    }
+    html.push_str("</tr></thead>\n<tbody>\n");
+    for (hour, minute) in &slots {
+        let _ = write!(html, "<tr><th>{:02}:{:02}</th>", hour, minute);
+        for weekday in WEEKDAYS {
+            html.push_str("<td>");
+            for (course, class) in &by_weekday[weekday.num_days_from_monday() as usize] {
+                if (class.period.start.hour, class.period.start.minute) == (*hour, *minute) {
+                    html.push_str(&render_block(course, class, show_private));
+                }
+            }
+            html.push_str("</td>");
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n</body>\n</html>\n");
+    html
+}
+
+fn render_block(course: &Course, class: &Class, show_private: bool) -> String {
+    let weeks = class
+        .weeks
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let color = course_color(&course.code);
+    let code = escape_html(&course.code);
+    let class_type = escape_html(&class.class_type);
+    if show_private {
+        format!(
+            "<div class=\"block\" style=\"background: {}\"><strong>{}</strong> {} ({})<br>{}<br>Weeks: {}</div>",
+            color,
+            code,
+            class_type,
+            escape_html(&course.index),
+            escape_html(&class.venue),
+            weeks
+        )
+    } else {
+        format!(
+            "<div class=\"block\" style=\"background: {}\"><strong>{}</strong> {}<br>Weeks: {}</div>",
+            color, code, class_type, weeks
+        )
+    }
+}
@@ -0,0 +1,298 @@
+//! Generates an RFC 5545 iCalendar (.ics) document from parsed [`Course`]s.
+
+use chrono::{Duration, NaiveDate, TimeZone};
+use chrono_tz::Tz;
+use ics::parameters::TzIDParam;
+use ics::properties::{Description, DtEnd, DtStart, ExDate, Location, RDate, RRule, Summary, Trigger};
+use ics::{Alarm, Event, ICalendar};
+
+use crate::calendar::{self, AcademicCalendar};
+use crate::course::{Class, Course, Exam};
+
+const PRODID: &str = "-//NTU_timetable_to_ICal//EN";
+const DATETIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+const UNTIL_DATETIME_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// Hours before an exam's start time that its `VALARM` reminder fires.
+pub const DEFAULT_EXAM_REMINDER_HOURS: i64 = 24;
+
+/// Default timezone classes and exams are rendered in, since NTU's
+/// timetable and exam schedules are always published in local Singapore time.
+pub const DEFAULT_TIMEZONE: Tz = chrono_tz::Asia::Singapore;
+
+/// Builds a `VCALENDAR` for `courses` using [`DEFAULT_EXAM_REMINDER_HOURS`]
+/// and [`DEFAULT_TIMEZONE`]. Use [`IcsExport`] directly to override either.
+pub fn courses_to_ics(courses: &[Course], academic_calendar: &AcademicCalendar) -> String {
+    IcsExport::new(courses, academic_calendar).build()
+}
+
+/// Options for exporting `courses` to an iCalendar document.
+pub struct IcsExport<'a> {
+    courses: &'a [Course],
+    academic_calendar: &'a AcademicCalendar,
+    exam_reminder_hours: i64,
+    tz: Tz,
+}
+
+impl<'a> IcsExport<'a> {
+    pub fn new(courses: &'a [Course], academic_calendar: &'a AcademicCalendar) -> Self {
+        Self {
+            courses,
+            academic_calendar,
+            exam_reminder_hours: DEFAULT_EXAM_REMINDER_HOURS,
+            tz: DEFAULT_TIMEZONE,
+        }
+    }
+
+    pub fn with_exam_reminder_hours(mut self, exam_reminder_hours: i64) -> Self {
+        self.exam_reminder_hours = exam_reminder_hours;
+        self
+    }
+
+    pub fn with_timezone(mut self, tz: Tz) -> Self {
+        self.tz = tz;
+        self
+    }
+
+    /// Builds a `VCALENDAR` containing one `VEVENT` per [`Class`] across
+    /// `courses`, plus one `VEVENT` per course exam, all timestamped in `tz`.
+    pub fn build(&self) -> String {
+        let mut calendar = ICalendar::new("2.0", PRODID);
+        let mut uid = 0u32;
+        for course in self.courses {
+            for class in &course.classes {
+                uid += 1;
+                calendar.add_event(class_to_event(course, class, self.academic_calendar, self.tz, uid));
+            }
+            if let Some(exam) = &course.exam {
+                calendar.add_event(exam_to_event(course, exam, self.exam_reminder_hours, self.tz));
+            }
+        }
+
+        let mut buf = Vec::new();
+        calendar
+            .write(&mut buf)
+            .expect("writing an iCalendar to an in-memory buffer cannot fail");
+        String::from_utf8(buf).expect("iCalendar output is ASCII-safe UTF-8")
+    }
+}
+
+fn class_to_event<'a>(
+    course: &Course,
+    class: &Class,
+    academic_calendar: &AcademicCalendar,
+    tz: Tz,
+    uid: u32,
+) -> Event<'a> {
+    let min_week = class.weeks.iter().min().copied().unwrap_or(1);
+    let max_week = class.weeks.iter().max().copied().unwrap_or(min_week);
+    let date = calendar::week_to_date(academic_calendar, min_week, class.weekday);
+    let dtstart = date
+        .and_hms_opt(class.period.start.hour, class.period.start.minute, 0)
+        .expect("class start time parsed from table is always valid");
+    let dtend = date
+        .and_hms_opt(class.period.end.hour, class.period.end.minute, 0)
+        .expect("class end time parsed from table is always valid");
+
+    let mut event = Event::new(
+        format!("{}-{}-{}@ntu-timetable-to-ical", course.code, class.class_type, uid),
+        dtstart.format(DATETIME_FORMAT).to_string(),
+    );
+    event.push(Summary::new(format!("{} {}", course.code, class.class_type)));
+    let mut dtstart_prop = DtStart::new(dtstart.format(DATETIME_FORMAT).to_string());
+    dtstart_prop.add(TzIDParam::new(tz.name()));
+    event.push(dtstart_prop);
+    let mut dtend_prop = DtEnd::new(dtend.format(DATETIME_FORMAT).to_string());
+    dtend_prop.add(TzIDParam::new(tz.name()));
+    event.push(dtend_prop);
+    event.push(Location::new(class.venue.clone()));
+    event.push(Description::new(format!(
+        "{}\\nGroup: {}\\nIndex: {}",
+        course.title, class.group, course.index
+    )));
+    push_recurrence(&mut event, class, academic_calendar, tz, min_week, max_week);
+    event
+}
+
+/// Expresses `class.weeks` as compactly as possible: a single weekly `RRULE`
+/// bounded by `UNTIL` with `EXDATE`s punched out for non-teaching weeks
+/// (e.g. recess), falling back to an explicit `RDATE` list when more weeks
+/// are missing than present, where enumerating is more compact than excluding.
+fn push_recurrence(
+    event: &mut Event,
+    class: &Class,
+    academic_calendar: &AcademicCalendar,
+    tz: Tz,
+    min_week: u32,
+    max_week: u32,
+) {
+    if min_week == max_week {
+        return;
+    }
+
+    let missing_weeks: Vec<u32> = (min_week..=max_week)
+        .filter(|week| !class.weeks.contains(week))
+        .collect();
+
+    if missing_weeks.len() > class.weeks.len() {
+        let rdates = class
+            .weeks
+            .iter()
+            .filter(|&&week| week != min_week)
+            .map(|&week| occurrence_datetime(academic_calendar, week, class))
+            .collect::<Vec<_>>()
+            .join(",");
+        if !rdates.is_empty() {
+            let mut rdate_prop = RDate::new(rdates);
+            rdate_prop.add(TzIDParam::new(tz.name()));
+            event.push(rdate_prop);
+        }
+        return;
+    }
+
+    let until = occurrence_datetime_utc(academic_calendar, max_week, class, tz);
+    event.push(RRule::new(format!("FREQ=WEEKLY;INTERVAL=1;UNTIL={}", until)));
+    // Walk every calendar week the RRULE spans, so inserted non-teaching weeks
+    // (which carry no teaching-week number) are excluded as well.
+    let occurrences: Vec<String> =
+        class.weeks.iter().map(|&week| occurrence_datetime(academic_calendar, week, class)).collect();
+    let first = calendar::week_to_date(academic_calendar, min_week, class.weekday);
+    let last = calendar::week_to_date(academic_calendar, max_week, class.weekday);
+    let exdates = (0..=(last - first).num_weeks())
+        .filter_map(|n| (first + Duration::weeks(n)).and_hms_opt(class.period.start.hour, class.period.start.minute, 0))
+        .map(|datetime| datetime.format(DATETIME_FORMAT).to_string())
+        .filter(|datetime| !occurrences.contains(datetime))
+        .collect::<Vec<_>>()
+        .join(",");
+    if !exdates.is_empty() {
+        let mut exdate_prop = ExDate::new(exdates);
+        exdate_prop.add(TzIDParam::new(tz.name()));
+        event.push(exdate_prop);
+    }
+}
+
+fn occurrence_datetime(academic_calendar: &AcademicCalendar, week: u32, class: &Class) -> String {
+    calendar::week_to_date(academic_calendar, week, class.weekday)
+        .and_hms_opt(class.period.start.hour, class.period.start.minute, 0)
+        .expect("class start time parsed from table is always valid")
+        .format(DATETIME_FORMAT)
+        .to_string()
+}
+
+/// Like [`occurrence_datetime`], but converted to UTC with a trailing `Z`.
+/// `RRULE`'s `UNTIL` must be expressed in UTC per RFC 5545 section 3.3.10 whenever
+/// `DTSTART` carries a `TZID`, unlike `EXDATE`/`RDATE`, which stay zoned.
+fn occurrence_datetime_utc(academic_calendar: &AcademicCalendar, week: u32, class: &Class, tz: Tz) -> String {
+    let local = calendar::week_to_date(academic_calendar, week, class.weekday)
+        .and_hms_opt(class.period.start.hour, class.period.start.minute, 0)
+        .expect("class start time parsed from table is always valid");
+    tz.from_local_datetime(&local)
+        .earliest()
+        .expect("a local wall-clock time always maps to at least one UTC instant")
+        .naive_utc()
+        .format(UNTIL_DATETIME_FORMAT)
+        .to_string()
+}
+
+fn exam_to_event<'a>(course: &Course, exam: &Exam, reminder_hours: i64, tz: Tz) -> Event<'a> {
+    let date = NaiveDate::from_ymd_opt(exam.year as i32, exam.month.number_from_month(), exam.day)
+        .expect("exam date parsed from table is always valid");
+    let dtstart = date
+        .and_hms_opt(exam.period.start.hour, exam.period.start.minute, 0)
+        .expect("exam start time parsed from table is always valid");
+    let dtend = date
+        .and_hms_opt(exam.period.end.hour, exam.period.end.minute, 0)
+        .expect("exam end time parsed from table is always valid");
+
+    let mut event = Event::new(
+        format!("{}-exam@ntu-timetable-to-ical", course.code),
+        dtstart.format(DATETIME_FORMAT).to_string(),
+    );
+    event.push(Summary::new(format!("EXAM {} {}", course.code, course.title)));
+    let mut dtstart_prop = DtStart::new(dtstart.format(DATETIME_FORMAT).to_string());
+    dtstart_prop.add(TzIDParam::new(tz.name()));
+    event.push(dtstart_prop);
+    let mut dtend_prop = DtEnd::new(dtend.format(DATETIME_FORMAT).to_string());
+    dtend_prop.add(TzIDParam::new(tz.name()));
+    event.push(dtend_prop);
+
+    let alarm = Alarm::display(
+        Trigger::new(format!("-PT{}H", reminder_hours)),
+        Description::new(format!("{} exam reminder", course.code)),
+    );
+    event.add_alarm(alarm);
+
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+
+    use super::*;
+    use crate::course::{Period, Time};
+
+    fn sample_class(weeks: Vec<u32>) -> Class {
+        Class {
+            weekday: Weekday::Mon,
+            period: Period {
+                start: Time { hour: 9, minute: 0 },
+                end: Time { hour: 10, minute: 0 },
+            },
+            venue: "LT1".into(),
+            group: "1".into(),
+            weeks,
+            class_type: "LEC".into(),
+        }
+    }
+
+    fn sample_calendar() -> AcademicCalendar {
+        AcademicCalendar::new(NaiveDate::from_ymd_opt(2024, 1, 8).unwrap())
+    }
+
+    fn render(event: Event) -> String {
+        let mut calendar = ICalendar::new("2.0", "-//test//EN");
+        calendar.add_event(event);
+        let mut buf = Vec::new();
+        calendar
+            .write(&mut buf)
+            .expect("writing to an in-memory buffer cannot fail");
+        String::from_utf8(buf).expect("test calendar output is ASCII-safe UTF-8")
+    }
+
+    #[test]
+    fn contiguous_weeks_emit_rrule_without_exdate_or_rdate() {
+        let academic_calendar = sample_calendar();
+        let class = sample_class((1..=6).collect());
+        let mut event = Event::new("test-uid", "20240108T000000");
+        push_recurrence(&mut event, &class, &academic_calendar, DEFAULT_TIMEZONE, 1, 6);
+        let rendered = render(event);
+        assert!(rendered.contains("RRULE:FREQ=WEEKLY;INTERVAL=1;UNTIL="));
+        assert!(!rendered.contains("EXDATE"));
+        assert!(!rendered.contains("RDATE"));
+    }
+
+    #[test]
+    fn recess_gap_emits_rrule_with_exdate() {
+        let academic_calendar = sample_calendar();
+        let weeks: Vec<u32> = (1..=6).chain(8..=13).collect();
+        let class = sample_class(weeks);
+        let mut event = Event::new("test-uid", "20240108T000000");
+        push_recurrence(&mut event, &class, &academic_calendar, DEFAULT_TIMEZONE, 1, 13);
+        let rendered = render(event);
+        assert!(rendered.contains("RRULE:FREQ=WEEKLY;INTERVAL=1;UNTIL="));
+        assert!(rendered.contains("EXDATE"));
+        assert!(!rendered.contains("RDATE"));
+    }
+
+    #[test]
+    fn sparse_weeks_fall_back_to_rdate() {
+        let academic_calendar = sample_calendar();
+        let class = sample_class(vec![1, 13]);
+        let mut event = Event::new("test-uid", "20240108T000000");
+        push_recurrence(&mut event, &class, &academic_calendar, DEFAULT_TIMEZONE, 1, 13);
+        let rendered = render(event);
+        assert!(!rendered.contains("RRULE"));
+        assert!(rendered.contains("RDATE"));
+    }
+}
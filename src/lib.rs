@@ -0,0 +1,4 @@
+pub mod calendar;
+pub mod course;
+pub mod html;
+pub mod ical;